@@ -1,8 +1,7 @@
-use std::sync::RwLockWriteGuard;
-
+use crate::sync::WriteGuard;
 use crate::{Entry, InnerRentVec};
 
-type Guard<'a, T> = RwLockWriteGuard<'a, InnerRentVec<T>>;
+type Guard<'a, T> = WriteGuard<'a, InnerRentVec<T>>;
 
 type FilterFn<T> = fn(&Entry<T>) -> Option<&T>;
 type Filter<T, I> = std::iter::FilterMap<I, FilterFn<T>>;
@@ -11,8 +10,10 @@ pub struct Iter<'a, T> {
 	inner: IterInner<'a, T>,
 }
 impl<'a, T> Iter<'a, T> {
-	pub(super) fn new(guard: &'a Guard<'a, T>) -> Self {
-		let slice = guard.items.iter();
+	// Takes the inner vec directly, rather than a specific guard type, so that
+	// both the exclusive RentVecGuard and the shared RentVecReadGuard can build one.
+	pub(super) fn new(inner: &'a InnerRentVec<T>) -> Self {
+		let slice = inner.items.iter();
 		let inner = slice.filter_map(Entry::owned as FilterFn<T>);
 		// No need to store the guard, since the reference will live as long as Self
 		Iter { inner }
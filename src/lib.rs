@@ -8,13 +8,13 @@
 //! let mut vec = RentVec::new();
 //! 
 //! let mut lease = vec.push(1u32);
-//! 
-//! let mut item = lease.guard();
+//!
+//! let mut item = lease.guard().unwrap();
 //! *item = 2;
 //! ```
 //! 
 //! The other way to access data is through the write guard and iterators, that guarantee that no lease is modifying its entry.
-//! 
+//!
 //! ```
 //! # use rent_vec::RentVec;
 //! # let mut vec = RentVec::<u32>::new();
@@ -22,7 +22,16 @@
 //! guard.iter();
 //! guard.iter_mut();
 //! ```
-//! 
+//!
+//! Read-only iteration can instead use a read guard, which multiple threads may hold at once.
+//!
+//! ```
+//! # use rent_vec::RentVec;
+//! # let vec = RentVec::<u32>::new();
+//! let guard = vec.read_guard();
+//! guard.iter();
+//! ```
+//!
 //! If an entry is removed, it will move an item from the back into its location, and mark the other as moved.
 //! 
 //! ```
@@ -33,55 +42,67 @@
 //! ```
 //! 
 //! Once a moved entry is accessed, the lease will become aware of the new location, and the moved entry can be freed.
-//! 
+//! If the slot has since been reused by something else entirely, `guard()` returns `None` instead of resolving to the wrong item.
+//!
 //! ## Why
 //! If you need a StableVec that is as contiguous as possible. The leases are also guaranteed to be valid.
 //! 
 //! ## Drawbacks
 //! The access performance is worse, since it has to resolve moved entries. After the first resolution, it is O(1).
-//! 
+//!
 //! Push performance is also slower, since it has to search for freed entries amongst moved entries. If there aren't moved entries, it is O(1).
+//!
+//! [Lease::guard] takes the write lock for as long as the returned [LeaseGuard] is held, not just
+//! while resolving a moved entry, so one outstanding lease guard blocks every other `push`,
+//! `remove`, `guard`, `read_guard` and `Lease::guard` call on the vector — leases aren't the
+//! independent, non-blocking handles the first example above might suggest.
+//!
+//! ## Features
+//! By default, `RentVec` uses `std::sync::RwLock` internally, so it is `Send + Sync`.
+//!
+//! - `parallel`: enabled by default. Disable it for single-threaded use, where `RentVec<T>` only
+//!   needs a `RefCell` and can hold a `T: !Sync`.
+//! - `parking_lot`: use `parking_lot`'s `RwLock` instead of the standard library's, when `parallel`
+//!   is enabled.
+//! - `serde`: `Serialize`/`Deserialize` for `RentVec<T>`, round-tripping through a plain sequence
+//!   of its items. Leases can't survive the trip, so a deserialized vector has no owners yet.
 
 pub mod iter;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod sync;
 
 use std::{
 	fmt::Display,
 	ops::{Deref, DerefMut},
-	sync::{RwLock, RwLockReadGuard, RwLockWriteGuard},
 };
 
 use iter::{Iter, IterMut};
+use sync::{read, write, Lock, ReadGuard, WriteGuard};
 
-fn write<T>(rwl: &RwLock<T>) -> RwLockWriteGuard<T> {
-	match rwl.write() {
-		Ok(g) => g,
-		Err(g) => g.into_inner(),
-	}
-}
-
-fn read<T>(rwl: &RwLock<T>) -> RwLockReadGuard<T> {
-	match rwl.read() {
-		Ok(g) => g,
-		Err(g) => g.into_inner(),
-	}
-}
+/// A slot's generation, bumped every time [RentVec::push] hands out a fresh one.
+///
+/// A [Lease] is tagged with the generation its slot held at the time, so a stale lease (one
+/// whose slot has since been freed and reused by something else) can be told apart from a live
+/// one, instead of the two being silently confused.
+type Generation = u64;
 
 #[derive(Debug, Clone)]
 pub enum Entry<T> {
 	Empty,
-	Owned(T),
-	Moved(usize),
+	Owned(Generation, T),
+	Moved(usize, Generation),
 }
 impl<T> Entry<T> {
 	fn owned(&self) -> Option<&T> {
-		if let Entry::Owned(t) = self {
+		if let Entry::Owned(_, t) = self {
 			Some(t)
 		} else {
 			None
 		}
 	}
 	fn owned_mut(&mut self) -> Option<&mut T> {
-		if let Entry::Owned(t) = self {
+		if let Entry::Owned(_, t) = self {
 			Some(t)
 		} else {
 			None
@@ -92,37 +113,49 @@ impl<T: Display> Display for Entry<T> {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		match self {
 			Entry::Empty => "Empty".fmt(f),
-			Entry::Owned(t) => write!(f, "Owned({})", t),
-			Entry::Moved(e) => write!(f, "Moved({})", e),
+			Entry::Owned(gen, t) => write!(f, "Owned({gen}, {t})"),
+			Entry::Moved(e, gen) => write!(f, "Moved({e}, {gen})"),
 		}
 	}
 }
 
 pub struct Lease<'v, T> {
 	entry: usize,
+	generation: Generation,
 	tenant: &'v RentVec<T>,
 }
 impl<'v, T> Lease<'v, T> {
-	pub fn guard(&mut self) -> LeaseGuard<'_, T> {
-		let guard = read(&self.tenant.lock);
-		let item = guard.items.get(self.entry).and_then(|mut item| loop {
-			match item {
-				Entry::Empty => None?,
-				Entry::Owned(t) => unsafe { break (t as *const T as *mut T).as_mut() },
-				Entry::Moved(e) => {
-					unsafe {
-						let item = item as *const Entry<T> as *mut Entry<T>;
-						*item = Entry::Empty
-					};
-					self.entry = *e;
-					item = guard.items.get(self.entry)?;
+	/// Resolves the lease to its current item, or `None` if it's gone: the slot has been freed
+	/// and (possibly) reused by something else since this lease last observed it.
+	///
+	/// Takes the write lock for the whole resolution, including following any [Entry::Moved]
+	/// chain: a [RentVecReadGuard] iterating with only a read lock must never be able to
+	/// observe (or alias) the `&mut T` this hands out, so the two can't be held at once.
+	///
+	/// The returned [LeaseGuard] keeps holding that same write lock until dropped, since the
+	/// lock is the only thing guaranteeing the `&mut T` stays exclusive — there's no per-slot
+	/// tracking of which entry is currently checked out. That means one held `LeaseGuard` blocks
+	/// every other operation on the whole vector, not just accesses to its own entry; see the
+	/// crate-level `Drawbacks` section.
+	pub fn guard(&mut self) -> Option<LeaseGuard<'_, T>> {
+		let mut guard = write(&self.tenant.lock);
+		loop {
+			match guard.items.get(self.entry)? {
+				Entry::Owned(gen, _) if *gen == self.generation => {
+					return Some(LeaseGuard {
+						entry: self.entry,
+						guard,
+					});
+				}
+				// Either never resolved to this lease's generation, or freed outright.
+				Entry::Owned(..) | Entry::Empty => return None,
+				Entry::Moved(next, gen) => {
+					let (next, gen) = (*next, *gen);
+					guard.items[self.entry] = Entry::Empty;
+					self.entry = next;
+					self.generation = gen;
 				}
 			}
-		}).unwrap();
-		// The guard can't be dropped here, or an iterator might write to this lease while it is being used
-		LeaseGuard {
-			item,
-			_guard: guard,
 		}
 	}
 	pub fn remove(self) {
@@ -130,18 +163,24 @@ impl<'v, T> Lease<'v, T> {
 	}
 }
 pub struct LeaseGuard<'l, T> {
-	item: &'l mut T,
-	_guard: RwLockReadGuard<'l, InnerRentVec<T>>,
+	entry: usize,
+	guard: WriteGuard<'l, InnerRentVec<T>>,
 }
 impl<'l, T> Deref for LeaseGuard<'l, T> {
 	type Target = T;
 	fn deref(&self) -> &Self::Target {
-		self.item
+		match &self.guard.items[self.entry] {
+			Entry::Owned(_, t) => t,
+			_ => unreachable!("guard() only ever builds a LeaseGuard pointing at an Owned entry"),
+		}
 	}
 }
 impl<'l, T> DerefMut for LeaseGuard<'l, T> {
 	fn deref_mut(&mut self) -> &mut Self::Target {
-		self.item
+		match &mut self.guard.items[self.entry] {
+			Entry::Owned(_, t) => t,
+			_ => unreachable!("guard() only ever builds a LeaseGuard pointing at an Owned entry"),
+		}
 	}
 }
 
@@ -149,30 +188,92 @@ impl<'l, T> DerefMut for LeaseGuard<'l, T> {
 struct InnerRentVec<T> {
 	/// The index of the first [Entry::Moved] / [Entry::Empty].
 	///
-	/// As such, all items before the tail are [Entry::Owned].
+	/// As such, all items before the tail are [Entry::Owned], and no items at or after the
+	/// tail are [Entry::Owned] — except that [RentVec::compact] can leave it as a conservative
+	/// lower bound: it never overwrites an un-followed [Entry::Moved] (see its doc comment), so
+	/// a still-Owned entry blocked behind one can remain at or after the reported tail. `push`
+	/// and `remove` stay correct either way, just without reusing that entry's slot until a
+	/// [Lease::guard] call resolves the blocking `Moved` and frees it.
 	///
-	/// And no items at or after the tail are [Entry::Owned].
+	/// `insert` can also move the tail past a non-Owned entry: it sets the tail to just past the
+	/// slot it filled, even when an [Entry::Moved] (or an [Entry::Empty] freed by
+	/// [Lease::guard]) lies below that slot. Such an `Empty` is not reused by `push` until
+	/// [RentVec::compact] recomputes the tail.
 	tail: usize,
 	items: Vec<Entry<T>>,
+	/// The generation to hand out to the next freshly-[Entry::Owned] slot.
+	next_generation: Generation,
 }
 impl<T> Default for InnerRentVec<T> {
 	fn default() -> Self {
 		Self {
 			tail: 0,
 			items: Vec::default(),
+			next_generation: 0,
 		}
 	}
 }
+impl<T> InnerRentVec<T> {
+	/// Inserts at the first [Entry::Empty], or appends a new one if there isn't one.
+	///
+	/// When the vector is already compact (`tail == items.len()`), this is O(1): there's
+	/// nothing to search, so the item is just pushed to the back.
+	fn insert(&mut self, item: T) -> (usize, Generation) {
+		let mut tail = self.tail;
+		let items = &mut self.items;
+		// If tail is equal to len, then there are no Empty entries
+		let entry = (tail != items.len())
+			.then(|| {
+				// Searches for the first Empty entry after the tail
+				loop {
+					let item = &items[tail];
+					match item {
+						Entry::Empty => {
+							break Some(tail);
+						}
+						// Usually unreachable, since no entries after the tail are Owned; but
+						// RentVec::compact can leave the tail conservative, stopped short at an
+						// un-followed Entry::Moved with a still-Owned entry behind it, so this
+						// has to keep searching rather than give up on a reusable Empty further
+						// down.
+						Entry::Owned(..) | Entry::Moved(..) => {
+							tail += 1;
+							// This case is possible if all entries past the tail are Owned/Moved
+							if tail == items.len() {
+								break None;
+							};
+						}
+					}
+				}
+			})
+			.flatten();
+		let generation = self.next_generation;
+		self.next_generation += 1;
+		let entry = match entry {
+			Some(entry) => {
+				items[entry] = Entry::Owned(generation, item);
+				entry
+			}
+			None => {
+				let entry = items.len();
+				items.push(Entry::Owned(generation, item));
+				entry
+			}
+		};
+		self.tail = self.tail.max(entry + 1);
+		(entry, generation)
+	}
+}
 
 #[derive(Debug)]
 pub struct RentVec<T> {
-	lock: RwLock<InnerRentVec<T>>,
+	lock: Lock<InnerRentVec<T>>,
 }
 
 impl<T> Default for RentVec<T> {
 	fn default() -> Self {
 		Self {
-			lock: RwLock::default(),
+			lock: Lock::default(),
 		}
 	}
 }
@@ -181,13 +282,25 @@ impl<T> RentVec<T> {
 	pub fn new() -> Self {
 		Self::default()
 	}
+	/// Builds a `RentVec` directly from its inner state, bypassing `push`.
+	///
+	/// Used by the `serde` support to rebuild an owner-less, fully-compacted vector.
+	pub(crate) fn from_inner(inner: InnerRentVec<T>) -> Self {
+		Self { lock: Lock::new(inner) }
+	}
 	/// Removes an entry, and inserts another one from the back in its place.
 	///
 	/// The other entry's old location, in turn, is set to [Entry::Moved].
 	fn remove(&self, entry: usize) -> Option<usize> {
 		let mut guard = write(&self.lock);
 
-		let mut replace = guard.tail - 1;
+		// `tail` can be conservative (see RentVec::compact, which stops it at the first
+		// non-Owned entry rather than guaranteeing one exists immediately below it), so there
+		// isn't always an Owned entry to swap in; fall back to a plain free when there isn't.
+		let Some(mut replace) = guard.tail.checked_sub(1) else {
+			guard.items[entry] = Entry::Empty;
+			return None;
+		};
 
 		let items = &mut guard.items;
 		if entry == replace {
@@ -200,15 +313,18 @@ impl<T> RentVec<T> {
 		loop {
 			let item = &mut items[replace];
 			match item {
-				// Should never be reached, since all entries before the tail are Owned
-				Entry::Empty | Entry::Moved(_) => {
-					// Replace will never reach zero
-					// The case where it would is handled above, when entry == replace
-					// In that case, the entry removed is the last Owned
-					replace -= 1
+				Entry::Empty | Entry::Moved(..) => {
+					replace = match replace.checked_sub(1) {
+						Some(replace) => replace,
+						None => {
+							items[entry] = Entry::Empty;
+							return None;
+						}
+					};
 				}
-				Entry::Owned(_) => {
-					let item = std::mem::replace(item, Entry::Moved(entry));
+				Entry::Owned(gen, _) => {
+					let gen = *gen;
+					let item = std::mem::replace(item, Entry::Moved(entry, gen));
 					items[entry] = item;
 					// Tail here is set to the first non-Owned entry
 					// That we just set to Moved two lines above
@@ -221,52 +337,41 @@ impl<T> RentVec<T> {
 	/// Inserts an entry at the first [Entry::Empty], or a new one if it doesn't exist.
 	pub fn push(&self, item: T) -> Lease<'_, T> {
 		let mut guard = write(&self.lock);
-		let mut tail = guard.tail;
-		let items = &mut guard.items;
-		// If tail is equal to len, then there are no Empty entries
-		let entry = (tail != items.len())
-			.then(|| {
-				// Searches for the first Empty entry after the tail
-				loop {
-					let item = &items[tail];
-					match item {
-						Entry::Empty => {
-							break Some(tail);
-						}
-						// Should never be reached, since no entries after the tail are Owned
-						Entry::Owned(_) => break None,
-						Entry::Moved(_) => {
-							// This case is possible if all entries past the tail are Moved
-							if tail == items.len() {
-								break None;
-							};
-							tail += 1
-						}
-					}
-				}
-			})
-			.flatten();
-		let entry = match entry {
-			Some(entry) => {
-				items[entry] = Entry::Owned(item);
-				entry
-			}
-			None => {
-				let entry = items.len();
-				items.push(Entry::Owned(item));
-				entry
-			}
-		};
-		guard.tail = guard.tail.max(entry + 1);
+		let (entry, generation) = guard.insert(item);
 		Lease {
 			entry,
+			generation,
 			tenant: self,
 		}
 	}
+	/// Reserves capacity for at least `additional` more items, as [Vec::reserve].
+	pub fn reserve(&self, additional: usize) {
+		write(&self.lock).items.reserve(additional);
+	}
+	/// Creates an empty `RentVec` with at least the specified capacity, as [Vec::with_capacity].
+	pub fn with_capacity(capacity: usize) -> Self {
+		Self::from_inner(InnerRentVec {
+			tail: 0,
+			items: Vec::with_capacity(capacity),
+			next_generation: 0,
+		})
+	}
+	/// Acquires the exclusive guard, allowing both [RentVecGuard::iter] and [RentVecGuard::iter_mut].
+	///
+	/// Since this takes the lock in write mode, it blocks other guards and leases until dropped.
+	/// For read-only iteration that doesn't block other readers, use [RentVec::read_guard] instead.
 	pub fn guard(&self) -> RentVecGuard<'_, T> {
 		let guard = write(&self.lock);
 		RentVecGuard { guard }
 	}
+	/// Acquires a shared guard, allowing concurrent, non-blocking [RentVecReadGuard::iter].
+	///
+	/// Mutating the items still requires the exclusive [RentVec::guard], since `iter_mut`
+	/// wouldn't be sound while other readers are iterating.
+	pub fn read_guard(&self) -> RentVecReadGuard<'_, T> {
+		let guard = read(&self.lock);
+		RentVecReadGuard { guard }
+	}
 	pub fn shrink(&self) {
 		let mut guard = write(&self.lock);
 		while let Some(Entry::Empty) = guard.items.last() {
@@ -275,16 +380,295 @@ impl<T> RentVec<T> {
 		guard.items.shrink_to_fit();
 		guard.tail = guard.tail.min(guard.items.len());
 	}
+	/// Reclaims [Entry::Moved] holes left scattered through the middle of the vector, not just
+	/// the trailing [Entry::Empty] ones [RentVec::shrink] pops.
+	///
+	/// Walks the items once, moving every live [Entry::Owned] down into the lowest
+	/// [Entry::Empty] slot found so far, and leaving a [Entry::Moved] forwarding pointer behind
+	/// exactly as [RentVec::remove] does. A lease still resolves this on its next
+	/// [Lease::guard] call, following the (possibly now longer) chain and freeing each hop it
+	/// passes through.
+	///
+	/// Existing [Entry::Moved] entries are never touched or used as a destination: until the
+	/// lease that owns one has followed it, its forwarding target must stay exactly where it
+	/// is, or that lease would resolve to the wrong item. Because of that, an un-followed
+	/// `Moved` can still block a live `Owned` entry behind it from moving down any further;
+	/// the new tail accounts for this and stops short rather than claiming a denser vector
+	/// than was actually achieved.
+	pub fn compact(&self) {
+		let mut guard = write(&self.lock);
+		let items = &mut guard.items;
+		let (mut w, mut r) = (0, items.len());
+		loop {
+			while w < r && !matches!(items[w], Entry::Empty) {
+				w += 1;
+			}
+			while w < r && !matches!(items[r - 1], Entry::Owned(..)) {
+				r -= 1;
+			}
+			if w >= r {
+				break;
+			}
+			r -= 1;
+			let gen = match &items[r] {
+				Entry::Owned(gen, _) => *gen,
+				_ => unreachable!("the backward scan above only stops on Entry::Owned"),
+			};
+			let item = std::mem::replace(&mut items[r], Entry::Moved(w, gen));
+			items[w] = item;
+			w += 1;
+		}
+		// An Entry::Moved (left by remove or an earlier compact) that its lease hasn't followed yet
+		// can still sit below the last Owned entry (it's never a valid destination, see above), so the
+		// tail has to stop at the first non-Owned entry rather than just past the last Owned
+		// one, or the "all items before the tail are Owned" invariant would break.
+		guard.tail = items
+			.iter()
+			.position(|entry| !matches!(entry, Entry::Owned(..)))
+			.unwrap_or(items.len());
+	}
+}
+
+impl<T> FromIterator<T> for RentVec<T> {
+	/// Builds a `RentVec` in a single pass, as a dense, fully-compacted vector of [Entry::Owned].
+	///
+	/// The returned leases are discarded, which is the common case for read-mostly workloads
+	/// populated up front. The items are then reachable only through [RentVec::guard] and
+	/// [RentVec::read_guard] iteration; no [Lease] can be obtained for them afterwards.
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+		let iter = iter.into_iter();
+		let mut items = Vec::with_capacity(iter.size_hint().0);
+		items.extend(
+			iter.enumerate()
+				.map(|(gen, item)| Entry::Owned(gen as Generation, item)),
+		);
+		let tail = items.len();
+		let next_generation = tail as Generation;
+		Self::from_inner(InnerRentVec {
+			tail,
+			items,
+			next_generation,
+		})
+	}
+}
+impl<T> Extend<T> for RentVec<T> {
+	/// Inserts every item as [RentVec::push] does (filling freed [Entry::Empty] slots first, then
+	/// appending), taking the write lock once instead of once per item.
+	///
+	/// The returned leases are discarded; see [FromIterator] above.
+	fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+		let mut guard = write(&self.lock);
+		for item in iter {
+			guard.insert(item);
+		}
+	}
 }
 
 pub struct RentVecGuard<'a, T> {
-	guard: RwLockWriteGuard<'a, InnerRentVec<T>>,
+	guard: WriteGuard<'a, InnerRentVec<T>>,
 }
 impl<'a, T> RentVecGuard<'a, T> {
 	pub fn iter(&self) -> Iter<'_, T> {
 		Iter::new(&self.guard)
 	}
+	/// Requires the exclusive [RentVec::guard], since mutating entries while another
+	/// thread iterates over them would be unsound.
 	pub fn iter_mut(&mut self) -> IterMut<'_, T> {
 		IterMut::new(&mut self.guard)
 	}
 }
+
+/// A shared guard allowing multiple threads to iterate the vector at once.
+///
+/// Holds a [ReadGuard](crate::sync::ReadGuard), so any number of read guards may coexist, but they
+/// still conflict with a concurrent [RentVec::guard] or [Lease::guard] (and vice versa) until
+/// dropped.
+pub struct RentVecReadGuard<'a, T> {
+	guard: ReadGuard<'a, InnerRentVec<T>>,
+}
+impl<'a, T> RentVecReadGuard<'a, T> {
+	pub fn iter(&self) -> Iter<'_, T> {
+		Iter::new(&self.guard)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn compact_fills_empty_slots_from_the_back() {
+		let vec = RentVec::from_inner(InnerRentVec {
+			items: vec![Entry::Empty, Entry::Owned(0, 'a'), Entry::Owned(1, 'b')],
+			tail: 3,
+			next_generation: 2,
+		});
+		vec.compact();
+		assert_eq!(vec.guard().iter().collect::<Vec<_>>(), vec![&'b', &'a']);
+		let inner = write(&vec.lock);
+		assert_eq!(inner.tail, 2);
+		assert!(matches!(inner.items[2], Entry::Moved(0, 1)));
+	}
+
+	#[test]
+	fn compact_stops_the_tail_at_an_unfollowed_moved_entry() {
+		// The Moved at index 2 hasn't been followed by its Lease yet, so it can't be used as
+		// a destination; that leaves the Owned entry behind it (index 3) stuck at or after the
+		// tail, which compact() must report honestly rather than claiming a denser vector.
+		let vec = RentVec::from_inner(InnerRentVec {
+			items: vec![
+				Entry::Owned(0, 'a'),
+				Entry::Owned(1, 'b'),
+				Entry::Moved(0, 2),
+				Entry::Owned(3, 'c'),
+				Entry::Empty,
+			],
+			tail: 4,
+			next_generation: 4,
+		});
+		vec.compact();
+		let inner = write(&vec.lock);
+		assert_eq!(inner.tail, 2);
+		assert!(matches!(inner.items[0], Entry::Owned(..)));
+		assert!(matches!(inner.items[1], Entry::Owned(..)));
+		assert!(matches!(inner.items[2], Entry::Moved(..)));
+	}
+
+	#[test]
+	fn push_after_compact_still_reuses_an_empty_slot_past_a_stuck_owned_entry() {
+		// Same conservative-tail layout as above: compact() can't fully pack this vector, so
+		// push() has to keep searching past the Owned entry it finds at/after the tail instead
+		// of giving up on the real Empty slot further down.
+		let vec = RentVec::from_inner(InnerRentVec {
+			items: vec![
+				Entry::Owned(0, 'a'),
+				Entry::Owned(1, 'b'),
+				Entry::Moved(0, 2),
+				Entry::Owned(3, 'c'),
+				Entry::Empty,
+			],
+			tail: 4,
+			next_generation: 4,
+		});
+		vec.compact();
+		vec.push('z');
+		let inner = write(&vec.lock);
+		assert_eq!(inner.items.len(), 5);
+		assert!(matches!(inner.items[4], Entry::Owned(_, 'z')));
+	}
+
+	#[test]
+	fn lease_guard_returns_none_once_its_slot_is_freed() {
+		let vec = RentVec::new();
+		let lease = vec.push(1u32);
+		lease.remove();
+
+		let mut stale = Lease {
+			entry: 0,
+			generation: 0,
+			tenant: &vec,
+		};
+		assert!(stale.guard().is_none());
+	}
+
+	#[test]
+	fn lease_guard_returns_none_once_its_slot_is_reused_by_another_generation() {
+		let vec = RentVec::new();
+		let lease = vec.push(1u32);
+		lease.remove();
+		vec.push(2u32);
+
+		let mut stale = Lease {
+			entry: 0,
+			generation: 0,
+			tenant: &vec,
+		};
+		assert!(stale.guard().is_none());
+	}
+
+	// Proves read_guard() actually lets two readers iterate at once, rather than just
+	// happening not to deadlock: each thread waits at the barrier *while still holding its
+	// guard*, so both must have acquired it concurrently for the test to complete at all.
+	#[cfg(feature = "parallel")]
+	#[test]
+	fn read_guard_allows_concurrent_iteration_across_threads() {
+		use std::sync::{Arc, Barrier};
+		use std::thread;
+
+		let vec = Arc::new(RentVec::new());
+		vec.push(1u32);
+		vec.push(2u32);
+
+		let barrier = Arc::new(Barrier::new(2));
+		let handles: Vec<_> = (0..2)
+			.map(|_| {
+				let vec = Arc::clone(&vec);
+				let barrier = Arc::clone(&barrier);
+				thread::spawn(move || {
+					let guard = vec.read_guard();
+					let items: Vec<_> = guard.iter().copied().collect();
+					barrier.wait();
+					items
+				})
+			})
+			.collect();
+		for handle in handles {
+			assert_eq!(handle.join().unwrap(), vec![1, 2]);
+		}
+	}
+
+	// Leases aren't part of the wire format (see src/serde_impl.rs), so the round trip is
+	// checked on the entries directly: values survive, and the moved/freed slot left behind by
+	// `remove` isn't carried across — the deserialized vector comes back fully compacted, with
+	// fresh generations starting from zero.
+	#[cfg(feature = "serde")]
+	#[test]
+	fn serde_round_trips_values_into_a_fresh_compacted_vec() {
+		let vec = RentVec::new();
+		let a = vec.push('a');
+		vec.push('b');
+		a.remove();
+		vec.push('c');
+
+		let json = serde_json::to_string(&vec).unwrap();
+		let restored: RentVec<char> = serde_json::from_str(&json).unwrap();
+
+		assert_eq!(
+			restored.guard().iter().collect::<Vec<_>>(),
+			vec![&'b', &'c'],
+		);
+		let inner = write(&restored.lock);
+		assert_eq!(inner.tail, inner.items.len());
+		assert!(matches!(inner.items[0], Entry::Owned(0, 'b')));
+		assert!(matches!(inner.items[1], Entry::Owned(1, 'c')));
+	}
+
+	#[test]
+	fn from_iterator_builds_a_dense_fully_compacted_vec() {
+		let vec: RentVec<u32> = (1..=3).collect();
+		assert_eq!(vec.guard().iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+		let inner = write(&vec.lock);
+		assert_eq!(inner.tail, inner.items.len());
+		assert!(inner.items.iter().all(|e| matches!(e, Entry::Owned(..))));
+	}
+
+	#[test]
+	fn extend_appends_every_item_under_a_single_lock_acquisition() {
+		let mut vec: RentVec<u32> = RentVec::new();
+		vec.push(1u32).remove();
+		vec.extend([2u32, 3u32]);
+		assert_eq!(vec.guard().iter().collect::<Vec<_>>(), vec![&2, &3]);
+	}
+
+	#[test]
+	fn with_capacity_starts_empty_and_reserve_does_not_change_contents() {
+		let vec: RentVec<u32> = RentVec::with_capacity(8);
+		assert!(write(&vec.lock).items.capacity() >= 8);
+		assert!(vec.guard().iter().next().is_none());
+
+		vec.reserve(4);
+		let lease = vec.push(1u32);
+		assert_eq!(vec.guard().iter().collect::<Vec<_>>(), vec![&1]);
+		lease.remove();
+	}
+}
@@ -0,0 +1,56 @@
+//! `serde` support for [RentVec].
+//!
+//! A `RentVec` serializes as a plain sequence of its [Entry::Owned] values; the `Empty`/`Moved`
+//! bookkeeping is never written to the wire, since it's meaningless without the process that
+//! produced it.
+//!
+//! Deserializing rebuilds a fresh, fully-compacted `InnerRentVec` (every entry `Owned`, `tail ==
+//! items.len()`). Leases can't survive serialization, so the result is an owner-less vector:
+//! its entries are reachable only through [RentVec::guard] and [RentVec::read_guard] iteration,
+//! and there is no way to obtain a [Lease](crate::Lease) for an entry already in the vector.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+
+use crate::{Entry, InnerRentVec, RentVec};
+
+impl<T: Serialize> Serialize for RentVec<T> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let guard = self.read_guard();
+		let mut seq = serializer.serialize_seq(None)?;
+		for item in guard.iter() {
+			seq.serialize_element(item)?;
+		}
+		seq.end()
+	}
+}
+
+struct RentVecVisitor<T>(PhantomData<T>);
+impl<'de, T: Deserialize<'de>> Visitor<'de> for RentVecVisitor<T> {
+	type Value = RentVec<T>;
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("a sequence of rented items")
+	}
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		let mut items = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+		while let Some(item) = seq.next_element()? {
+			items.push(Entry::Owned(items.len() as crate::Generation, item));
+		}
+		let tail = items.len();
+		let next_generation = tail as crate::Generation;
+		Ok(RentVec::from_inner(InnerRentVec {
+			tail,
+			items,
+			next_generation,
+		}))
+	}
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for RentVec<T> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserializer.deserialize_seq(RentVecVisitor(PhantomData))
+	}
+}
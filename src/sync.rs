@@ -0,0 +1,63 @@
+//! The lock backend used internally by [RentVec](crate::RentVec).
+//!
+//! Mirrors the approach taken by rustc's `rustc_data_structures::sync`: the lock type is chosen
+//! at compile time, so that single-threaded users don't pay for a real lock, and `RentVec<T>` can
+//! hold a `T: !Sync` when built without the `parallel` feature.
+//!
+//! - With the `parallel` feature enabled, [Lock] is a real reader-writer lock
+//!   ([std::sync::RwLock], or [parking_lot::RwLock] if the `parking_lot` feature is also
+//!   enabled), and `RentVec` is `Send + Sync`.
+//! - With `parallel` disabled, [Lock] is a [RefCell], and `read`/`write` collapse to cheap
+//!   borrows, which panic on a conflicting borrow instead of blocking.
+
+#[cfg(all(feature = "parallel", feature = "parking_lot"))]
+mod backend {
+	pub use parking_lot::{
+		RwLock as Lock, RwLockReadGuard as ReadGuard, RwLockWriteGuard as WriteGuard,
+	};
+
+	pub fn read<T>(lock: &Lock<T>) -> ReadGuard<'_, T> {
+		lock.read()
+	}
+	pub fn write<T>(lock: &Lock<T>) -> WriteGuard<'_, T> {
+		lock.write()
+	}
+}
+
+#[cfg(all(feature = "parallel", not(feature = "parking_lot")))]
+mod backend {
+	pub use std::sync::{RwLock as Lock, RwLockReadGuard as ReadGuard, RwLockWriteGuard as WriteGuard};
+
+	// Poisoning only happens when a panic unwinds while the lock is held, in which case the
+	// partially-updated state is still a valid `InnerRentVec`, so recovering it is safe.
+	pub fn read<T>(lock: &Lock<T>) -> ReadGuard<'_, T> {
+		match lock.read() {
+			Ok(g) => g,
+			Err(g) => g.into_inner(),
+		}
+	}
+	pub fn write<T>(lock: &Lock<T>) -> WriteGuard<'_, T> {
+		match lock.write() {
+			Ok(g) => g,
+			Err(g) => g.into_inner(),
+		}
+	}
+}
+
+#[cfg(not(feature = "parallel"))]
+mod backend {
+	use std::cell::{Ref, RefCell, RefMut};
+
+	pub use std::cell::RefCell as Lock;
+	pub type ReadGuard<'a, T> = Ref<'a, T>;
+	pub type WriteGuard<'a, T> = RefMut<'a, T>;
+
+	pub fn read<T>(lock: &RefCell<T>) -> Ref<'_, T> {
+		lock.borrow()
+	}
+	pub fn write<T>(lock: &RefCell<T>) -> RefMut<'_, T> {
+		lock.borrow_mut()
+	}
+}
+
+pub use backend::{read, write, Lock, ReadGuard, WriteGuard};